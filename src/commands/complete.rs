@@ -0,0 +1,48 @@
+use clap_complete::engine::CompletionCandidate;
+use std::ffi::OsStr;
+
+use crate::commands::config::{config_dir, QemuConfig};
+
+/// Complete a saved config name, annotated with its description when available.
+///
+/// Attached to the `name`/`old_name`/`new_name` arguments of `exec`, `rm`,
+/// `rename`, and `print` so the shell always sees the current `~/.vex/configs`
+/// contents instead of a stale, separately-maintained list.
+pub fn config_name_completer(current: &OsStr) -> Vec<CompletionCandidate> {
+    let Some(current) = current.to_str() else {
+        return Vec::new();
+    };
+
+    let Ok(dir) = config_dir() else {
+        return Vec::new();
+    };
+    let Ok(entries) = std::fs::read_dir(&dir) else {
+        return Vec::new();
+    };
+
+    entries
+        .flatten()
+        .filter_map(|entry| {
+            let path = entry.path();
+            if path.extension().and_then(|ext| ext.to_str()) != Some("json") {
+                return None;
+            }
+
+            let name = path.file_stem().and_then(|s| s.to_str())?.to_string();
+            if !name.starts_with(current) {
+                return None;
+            }
+
+            let desc = std::fs::read_to_string(&path)
+                .ok()
+                .and_then(|content| serde_json::from_str::<QemuConfig>(&content).ok())
+                .and_then(|config| config.desc);
+
+            let candidate = CompletionCandidate::new(name);
+            Some(match desc {
+                Some(desc) => candidate.help(Some(desc.into())),
+                None => candidate,
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,60 @@
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+
+/// QEMU executable used when neither a config nor the global defaults set one
+pub const DEFAULT_QEMU_BIN: &str = "qemu-system-x86_64";
+
+/// Stored QEMU configuration structure
+#[derive(Debug, Serialize, Deserialize)]
+pub struct QemuConfig {
+    /// Path to QEMU executable; falls back to the global default, then
+    /// [`DEFAULT_QEMU_BIN`], when omitted
+    pub qemu_bin: Option<String>,
+    /// List of QEMU startup arguments
+    pub args: Vec<String>,
+    /// Configuration description (optional)
+    pub desc: Option<String>,
+    /// Path to a Lua script that builds the final argv at exec time (optional)
+    #[serde(default)]
+    pub script: Option<String>,
+    /// Names of reusable feature blocks (see `vex feature`) to splice in at exec time
+    #[serde(default)]
+    pub features: Vec<String>,
+}
+
+/// Get Vex's top-level state directory (~/.vex)
+pub fn vex_dir() -> Result<PathBuf> {
+    let home = dirs::home_dir().context("Failed to get user home directory")?;
+    Ok(home.join(".vex"))
+}
+
+/// Get Vex config file storage directory (~/.vex/configs)
+pub fn config_dir() -> Result<PathBuf> {
+    let dir = vex_dir()?.join("configs");
+    if !dir.exists() {
+        fs::create_dir_all(&dir).context("Failed to create config directory")?;
+    }
+    Ok(dir)
+}
+
+/// Get path to the config file for a given name
+pub fn config_file(name: &str) -> Result<PathBuf> {
+    let dir = config_dir()?;
+    Ok(dir.join(format!("{}.json", name)))
+}
+
+/// Load and deserialize the configuration for a given name
+pub fn load_config(name: &str) -> Result<QemuConfig> {
+    let config_path = config_file(name)?;
+    if !config_path.exists() {
+        anyhow::bail!(
+            "Configuration '{}' does not exist. Create it first with 'vex save'",
+            name
+        );
+    }
+
+    let config_json = fs::read_to_string(&config_path).context("Failed to read config file")?;
+    serde_json::from_str(&config_json).context("Failed to deserialize configuration")
+}
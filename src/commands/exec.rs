@@ -0,0 +1,146 @@
+use anyhow::{Context, Result};
+use clap::Args;
+use std::collections::HashMap;
+use std::fs;
+use std::process::{Command, Stdio};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::commands::config::load_config;
+use crate::commands::global;
+use crate::commands::qmp::{qmp_socket_arg, qmp_socket_path};
+use crate::commands::run::{self, RunRecord};
+
+#[derive(Args)]
+#[clap(about = "Execute a saved QEMU configuration")]
+pub struct ExecArgs {
+    /// Name of the configuration to execute
+    pub name: String,
+
+    /// Enable debug mode (adds -s -S parameters for GDB debugging; a config
+    /// with a script only receives it as `vm.debug` and gets no -s -S added)
+    #[arg(short = 'd')]
+    pub debug: bool,
+
+    /// Variables passed to the config script as `vm.vars.<key>` (key=value)
+    #[arg(long = "var", value_name = "KEY=VALUE")]
+    pub vars: Vec<String>,
+
+    /// Spawn QEMU detached and free the terminal immediately; manage it with
+    /// `vex ps` / `vex stop`
+    #[arg(long)]
+    pub detach: bool,
+}
+
+pub fn exec_command(args: ExecArgs) -> Result<()> {
+    let ExecArgs {
+        name,
+        debug,
+        vars,
+        detach,
+    } = args;
+    let config = load_config(&name)?;
+    let global_config = global::load_global()?;
+    let (qemu_bin, _) = global::effective_qemu_bin(&config, &global_config);
+
+    let vars = parse_vars(&vars);
+    let mut exec_args: Vec<String> =
+        global::effective_args(&name, &config, &global_config, debug, &vars)?
+            .into_iter()
+            .map(|arg| arg.value)
+            .collect();
+
+    // A stale socket from a previous run would make QEMU refuse to bind as the server
+    let socket = qmp_socket_path(&name)?;
+    let _ = fs::remove_file(&socket);
+    exec_args.push("-qmp".to_string());
+    exec_args.push(qmp_socket_arg(&socket));
+
+    if debug {
+        if let Some(desc) = &config.desc {
+            println!(
+                "Starting configuration '{}' ({}) in DEBUG mode: {} {:?}",
+                name, desc, qemu_bin, exec_args
+            );
+        } else {
+            println!(
+                "Starting configuration '{}' in DEBUG mode: {} {:?}",
+                name, qemu_bin, exec_args
+            );
+        }
+        if exec_args.iter().any(|arg| arg == "-s") {
+            println!("GDB debugging server started, you can connect to localhost:1234 using gdb");
+        }
+    } else if let Some(desc) = &config.desc {
+        println!(
+            "Starting configuration '{}' ({}): {} {:?}",
+            name, desc, qemu_bin, exec_args
+        );
+    } else {
+        println!(
+            "Starting configuration '{}': {} {:?}",
+            name, qemu_bin, exec_args
+        );
+    }
+    println!(
+        "QMP control socket: {} (use 'vex monitor {} <qmp-command>' to control this instance)",
+        socket.display(),
+        name
+    );
+
+    let mut command = Command::new(&qemu_bin);
+    command.args(&exec_args);
+    if detach {
+        command
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null());
+    }
+
+    let mut child = command
+        .spawn()
+        .with_context(|| format!("Failed to execute QEMU: {}", qemu_bin))?;
+
+    let mut command_line = vec![qemu_bin.clone()];
+    command_line.extend(exec_args.clone());
+    run::write_record(&RunRecord {
+        name: name.clone(),
+        pid: child.id(),
+        started_at: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .context("System clock is before the Unix epoch")?
+            .as_secs(),
+        command_line,
+        qmp_socket: socket.clone(),
+    })?;
+
+    if detach {
+        println!(
+            "Started '{}' detached (pid {}); use 'vex ps' to check status and 'vex stop {}' to shut it down",
+            name, child.id(), name
+        );
+        return Ok(());
+    }
+
+    let status = child
+        .wait()
+        .with_context(|| format!("Failed to wait on QEMU: {}", qemu_bin))?;
+    run::remove_record(&name)?;
+
+    if !status.success() {
+        anyhow::bail!(
+            "QEMU execution failed with exit code: {}",
+            status.code().unwrap_or(-1)
+        );
+    }
+
+    Ok(())
+}
+
+/// Turn `key=value` pairs from `--var` into a lookup table for the config script
+fn parse_vars(pairs: &[String]) -> HashMap<String, String> {
+    pairs
+        .iter()
+        .filter_map(|pair| pair.split_once('='))
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .collect()
+}
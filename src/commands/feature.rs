@@ -0,0 +1,243 @@
+use anyhow::{Context, Result};
+use clap::{Args, Subcommand};
+use std::collections::HashSet;
+use std::fs;
+use std::path::PathBuf;
+
+use crate::commands::config::vex_dir;
+
+#[derive(Args)]
+#[clap(about = "Manage reusable QEMU argument feature blocks")]
+pub struct FeatureArgs {
+    #[command(subcommand)]
+    pub action: FeatureAction,
+}
+
+#[derive(Subcommand)]
+pub enum FeatureAction {
+    /// Save a feature block (e.g. uefi, spice, pulse, looking-glass)
+    Save {
+        /// Feature name
+        name: String,
+
+        /// QEMU arguments this feature expands to (e.g. -vga qxl -spice port=5930)
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
+
+    /// List saved feature blocks
+    List,
+
+    /// Remove a saved feature block
+    Rm {
+        /// Feature name
+        name: String,
+    },
+}
+
+/// Get Vex feature block storage directory (~/.vex/features)
+pub fn feature_dir() -> Result<PathBuf> {
+    let dir = vex_dir()?.join("features");
+    if !dir.exists() {
+        fs::create_dir_all(&dir).context("Failed to create feature directory")?;
+    }
+    Ok(dir)
+}
+
+fn feature_file(name: &str) -> Result<PathBuf> {
+    Ok(feature_dir()?.join(format!("{}.json", name)))
+}
+
+/// Load the args a feature block expands to
+pub fn load_feature(name: &str) -> Result<Vec<String>> {
+    let path = feature_file(name)?;
+    if !path.exists() {
+        anyhow::bail!(
+            "Feature '{}' does not exist. Create it first with 'vex feature save'",
+            name
+        );
+    }
+
+    let json = fs::read_to_string(&path).context("Failed to read feature file")?;
+    serde_json::from_str(&json).context("Failed to deserialize feature")
+}
+
+/// QEMU flags that only make sense once; safe to drop a later occurrence in
+/// favor of one the config (or an earlier feature) already set. Everything
+/// else (`-device`, `-drive`, `-object`, `-netdev`, `-chardev`, ...) is
+/// repeatable and must never be deduplicated.
+const NON_REPEATABLE_FLAGS: &[&str] = &[
+    "-m", "-machine", "-cpu", "-smp", "-boot", "-name", "-vga", "-display", "-accel",
+];
+
+/// Resolve a config's `features` list into a single argv, spliced ahead of
+/// `existing_args` and skipping any non-repeatable flag the config (or an
+/// earlier feature) already sets itself.
+pub fn resolve(features: &[String], existing_args: &[String]) -> Result<Vec<String>> {
+    let mut loaded_features = Vec::new();
+    for feature in features {
+        loaded_features.push(load_feature(feature)?);
+    }
+
+    Ok(merge_feature_args(&loaded_features, existing_args))
+}
+
+/// Pure dedup/merge logic, split out from `resolve` so it can be tested
+/// without touching `~/.vex/features`.
+fn merge_feature_args(features: &[Vec<String>], existing_args: &[String]) -> Vec<String> {
+    let mut seen_flags = flags_in(existing_args);
+    let mut resolved = Vec::new();
+
+    for args in features {
+        let mut iter = args.iter().cloned().peekable();
+        // Flags this feature itself introduces; merged into `seen_flags` only
+        // after the feature is done, so repeats within the same feature are
+        // never suppressed against each other.
+        let mut added_by_this_feature = HashSet::new();
+
+        while let Some(flag) = iter.next() {
+            if !flag.starts_with('-') {
+                resolved.push(flag);
+                continue;
+            }
+
+            let value = match iter.peek() {
+                Some(next) if !next.starts_with('-') => iter.next(),
+                _ => None,
+            };
+
+            let non_repeatable = NON_REPEATABLE_FLAGS.contains(&flag.as_str());
+            if non_repeatable && seen_flags.contains(&flag) {
+                // The config, global defaults, or an earlier feature already set this flag
+                continue;
+            }
+
+            resolved.push(flag.clone());
+            if let Some(value) = value {
+                resolved.push(value);
+            }
+
+            if non_repeatable {
+                added_by_this_feature.insert(flag);
+            }
+        }
+
+        seen_flags.extend(added_by_this_feature);
+    }
+
+    resolved
+}
+
+fn flags_in(args: &[String]) -> HashSet<String> {
+    args.iter()
+        .filter(|arg| arg.starts_with('-'))
+        .cloned()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn repeatable_flags_survive_within_a_feature() {
+        let pulse = vec![
+            "-device".to_string(),
+            "intel-hda".to_string(),
+            "-device".to_string(),
+            "hda-duplex".to_string(),
+        ];
+
+        let resolved = merge_feature_args(&[pulse], &[]);
+
+        assert_eq!(
+            resolved,
+            vec!["-device", "intel-hda", "-device", "hda-duplex"]
+        );
+    }
+
+    #[test]
+    fn non_repeatable_flag_already_set_by_config_is_skipped() {
+        let feature = vec!["-vga".to_string(), "qxl".to_string()];
+        let existing = vec!["-vga".to_string(), "std".to_string()];
+
+        let resolved = merge_feature_args(&[feature], &existing);
+
+        assert!(resolved.is_empty());
+    }
+
+    #[test]
+    fn non_repeatable_duplicate_within_one_feature_is_kept() {
+        let feature = vec![
+            "-m".to_string(),
+            "512".to_string(),
+            "-m".to_string(),
+            "1024".to_string(),
+        ];
+
+        let resolved = merge_feature_args(&[feature], &[]);
+
+        assert_eq!(resolved, vec!["-m", "512", "-m", "1024"]);
+    }
+
+    #[test]
+    fn non_repeatable_flag_from_earlier_feature_is_skipped_in_later_one() {
+        let uefi = vec!["-machine".to_string(), "q35".to_string()];
+        let spice = vec!["-machine".to_string(), "pc".to_string()];
+
+        let resolved = merge_feature_args(&[uefi, spice], &[]);
+
+        assert_eq!(resolved, vec!["-machine", "q35"]);
+    }
+}
+
+pub fn feature_command(args: FeatureArgs) -> Result<()> {
+    match args.action {
+        FeatureAction::Save { name, args } => {
+            let path = feature_file(&name)?;
+            let json =
+                serde_json::to_string_pretty(&args).context("Failed to serialize feature")?;
+            fs::write(&path, json).context("Failed to save feature file")?;
+            println!("Feature '{}' saved to {:?}", name, path);
+        }
+
+        FeatureAction::List => {
+            let dir = feature_dir()?;
+            let entries = fs::read_dir(&dir).context("Failed to read feature directory")?;
+            let mut features = Vec::new();
+
+            for entry in entries {
+                let entry = entry.context("Failed to read directory entry")?;
+                let path = entry.path();
+                if path.extension().map_or(false, |ext| ext == "json") {
+                    if let Some(name) = path.file_stem().and_then(|s| s.to_str()) {
+                        if let Ok(args) = load_feature(name) {
+                            features.push((name.to_string(), args));
+                        }
+                    }
+                }
+            }
+
+            if features.is_empty() {
+                println!("No features saved yet.");
+            } else {
+                println!("Saved features:");
+                for (name, args) in features {
+                    println!("  {} - {:?}", name, args);
+                }
+            }
+        }
+
+        FeatureAction::Rm { name } => {
+            let path = feature_file(&name)?;
+            if !path.exists() {
+                anyhow::bail!("Feature '{}' does not exist, cannot delete", name);
+            }
+
+            fs::remove_file(&path).context("Failed to delete feature file")?;
+            println!("Feature '{}' deleted", name);
+        }
+    }
+
+    Ok(())
+}
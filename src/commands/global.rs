@@ -0,0 +1,257 @@
+use anyhow::{Context, Result};
+use clap::{Args, Subcommand};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::commands::config::{load_config, vex_dir, QemuConfig, DEFAULT_QEMU_BIN};
+use crate::commands::feature;
+use crate::commands::lua;
+
+/// Global defaults shared by every config unless it overrides them
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct GlobalConfig {
+    /// Default QEMU executable, used when a config omits `qemu_bin`
+    #[serde(default)]
+    pub qemu_bin: Option<String>,
+    /// Baseline QEMU arguments applied ahead of every config's own args
+    /// (not applied to a config with a script)
+    #[serde(default)]
+    pub args: Vec<String>,
+}
+
+/// Which layer an effective value came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Origin {
+    Default,
+    Global,
+    Config,
+    CommandArg,
+}
+
+impl fmt::Display for Origin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Origin::Default => "Default",
+            Origin::Global => "Global",
+            Origin::Config => "Config",
+            Origin::CommandArg => "CommandArg",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// A single effective argument, tagged with the layer it was resolved from
+pub struct EffectiveArg {
+    pub value: String,
+    pub origin: Origin,
+}
+
+#[derive(Args)]
+#[clap(about = "Inspect and set global defaults")]
+pub struct ConfigArgs {
+    #[command(subcommand)]
+    pub action: ConfigAction,
+}
+
+#[derive(Subcommand)]
+pub enum ConfigAction {
+    /// Set the global default QEMU binary and/or baseline arguments
+    Set {
+        /// Default QEMU executable (e.g., qemu-system-x86_64)
+        #[arg(long)]
+        qemu_bin: Option<String>,
+
+        /// Baseline QEMU arguments applied ahead of every config without a script
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
+
+    /// Print the effective (merged) configuration for a saved config
+    Print {
+        /// Name of the configuration to print
+        name: String,
+
+        /// Enable debug mode, so the CommandArg layer's -s/-S show up too
+        /// (a config with a script only receives it as `vm.debug`)
+        #[arg(short = 'd', long)]
+        debug: bool,
+
+        /// Tag each effective argument with the layer it came from
+        #[arg(long)]
+        origin: bool,
+    },
+}
+
+fn primary_path() -> Result<PathBuf> {
+    Ok(vex_dir()?.join("global.json"))
+}
+
+/// Legacy XDG location; kept around so a stray file there is caught as a
+/// conflict instead of silently shadowing (or being shadowed by) the primary one
+fn legacy_path() -> Result<PathBuf> {
+    let home = dirs::home_dir().context("Failed to get user home directory")?;
+    Ok(home.join(".config").join("vex").join("global.json"))
+}
+
+/// Load the global defaults, erroring if both the primary and legacy
+/// locations exist rather than silently picking one.
+pub fn load_global() -> Result<GlobalConfig> {
+    let primary = primary_path()?;
+    let legacy = legacy_path()?;
+
+    match (primary.exists(), legacy.exists()) {
+        (true, true) => anyhow::bail!(
+            "Conflicting global config files found at {:?} and {:?}; remove one",
+            primary,
+            legacy
+        ),
+        (true, false) => read_global(&primary),
+        (false, true) => read_global(&legacy),
+        (false, false) => Ok(GlobalConfig::default()),
+    }
+}
+
+fn read_global(path: &PathBuf) -> Result<GlobalConfig> {
+    let json = fs::read_to_string(path).context("Failed to read global config file")?;
+    serde_json::from_str(&json).context("Failed to deserialize global config")
+}
+
+fn save_global(global: &GlobalConfig) -> Result<PathBuf> {
+    let path = primary_path()?;
+    let json =
+        serde_json::to_string_pretty(global).context("Failed to serialize global config")?;
+    fs::write(&path, json).context("Failed to save global config file")?;
+    Ok(path)
+}
+
+/// Resolve the QEMU binary to run: Config, then Global, then [`DEFAULT_QEMU_BIN`]
+pub fn effective_qemu_bin(config: &QemuConfig, global: &GlobalConfig) -> (String, Origin) {
+    if let Some(qemu_bin) = &config.qemu_bin {
+        return (qemu_bin.clone(), Origin::Config);
+    }
+    if let Some(qemu_bin) = &global.qemu_bin {
+        return (qemu_bin.clone(), Origin::Global);
+    }
+    (DEFAULT_QEMU_BIN.to_string(), Origin::Default)
+}
+
+/// Merge Global -> Config (features then static args) -> CommandArg layers
+pub fn layered_args(
+    config: &QemuConfig,
+    global: &GlobalConfig,
+    debug: bool,
+) -> Result<Vec<EffectiveArg>> {
+    let mut effective = Vec::new();
+
+    for arg in &global.args {
+        effective.push(EffectiveArg {
+            value: arg.clone(),
+            origin: Origin::Global,
+        });
+    }
+
+    let existing = [global.args.as_slice(), config.args.as_slice()].concat();
+    for arg in feature::resolve(&config.features, &existing)? {
+        effective.push(EffectiveArg {
+            value: arg,
+            origin: Origin::Config,
+        });
+    }
+
+    for arg in &config.args {
+        effective.push(EffectiveArg {
+            value: arg.clone(),
+            origin: Origin::Config,
+        });
+    }
+
+    if debug {
+        effective.push(EffectiveArg {
+            value: "-s".to_string(),
+            origin: Origin::CommandArg,
+        });
+        effective.push(EffectiveArg {
+            value: "-S".to_string(),
+            origin: Origin::CommandArg,
+        });
+    }
+
+    Ok(effective)
+}
+
+/// Resolve the QEMU args `vex exec` would actually run: the config's script
+/// if it has one (its argv is used as-is: global args, features and the
+/// debug -s/-S are not layered in; the script gets the debug flag as
+/// `vm.debug` and the `--var` pairs as `vm.vars`), otherwise
+/// [`layered_args`]. Callers that only need to display/export the command
+/// (`print --cmdline`, `config print`) go through this too, so what they
+/// show matches what `exec` runs, except for the `-qmp` socket argument
+/// that `exec` appends itself.
+pub fn effective_args(
+    name: &str,
+    config: &QemuConfig,
+    global: &GlobalConfig,
+    debug: bool,
+    vars: &HashMap<String, String>,
+) -> Result<Vec<EffectiveArg>> {
+    if let Some(script) = &config.script {
+        let args = lua::build_args(Path::new(script), name, config.desc.as_deref(), debug, vars)?;
+        return Ok(args
+            .into_iter()
+            .map(|value| EffectiveArg {
+                value,
+                origin: Origin::Config,
+            })
+            .collect());
+    }
+
+    layered_args(config, global, debug)
+}
+
+pub fn config_command(args: ConfigArgs) -> Result<()> {
+    match args.action {
+        ConfigAction::Set { qemu_bin, args } => {
+            let mut global = load_global()?;
+            if qemu_bin.is_some() {
+                global.qemu_bin = qemu_bin;
+            }
+            if !args.is_empty() {
+                global.args = args;
+            }
+
+            let path = save_global(&global)?;
+            println!("Global defaults saved to {:?}", path);
+        }
+
+        ConfigAction::Print {
+            name,
+            debug,
+            origin,
+        } => {
+            let config = load_config(&name)?;
+
+            let global = load_global()?;
+            let (qemu_bin, qemu_bin_origin) = effective_qemu_bin(&config, &global);
+            let args = effective_args(&name, &config, &global, debug, &HashMap::new())?;
+
+            if origin {
+                println!("qemu_bin: {} ({})", qemu_bin, qemu_bin_origin);
+                println!("args:");
+                for arg in args {
+                    println!("  {} ({})", arg.value, arg.origin);
+                }
+            } else {
+                println!("qemu_bin: {}", qemu_bin);
+                println!(
+                    "args: {:?}",
+                    args.into_iter().map(|arg| arg.value).collect::<Vec<_>>()
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
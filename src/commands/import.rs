@@ -0,0 +1,70 @@
+use anyhow::{Context, Result};
+use clap::Args;
+use std::fs;
+
+use crate::commands::config::{config_file, QemuConfig};
+
+#[derive(Args)]
+#[clap(about = "Import a full QEMU command line as a configuration")]
+pub struct ImportArgs {
+    /// Configuration name to import into
+    pub name: String,
+
+    /// Force overwrite existing configuration (no prompt)
+    #[arg(short = 'y')]
+    pub force: bool,
+
+    /// Configuration description (optional, use double quotes)
+    #[arg(short = 'd')]
+    pub desc: Option<String>,
+
+    /// Full QEMU invocation, e.g. `vex import myvm -- qemu-system-x86_64 -m 512 -hda disk.img`
+    #[arg(trailing_var_arg = true, allow_hyphen_values = true, last = true)]
+    pub command: Vec<String>,
+}
+
+pub fn import_command(args: ImportArgs) -> Result<()> {
+    let ImportArgs {
+        name,
+        force,
+        desc,
+        command,
+    } = args;
+
+    let mut command = command.into_iter();
+    let qemu_bin = command
+        .next()
+        .context("No command given; usage: vex import <name> -- <qemu command>")?;
+    let qemu_args: Vec<String> = command.collect();
+
+    let config_path = config_file(&name)?;
+    if config_path.exists() && !force {
+        println!("Configuration '{}' already exists, overwrite? [y/N]", name);
+        let mut input = String::new();
+        std::io::stdin().read_line(&mut input)?;
+        let input = input.trim().to_lowercase();
+        if input != "y" && input != "yes" {
+            println!("Import cancelled");
+            return Ok(());
+        }
+    }
+
+    let config = QemuConfig {
+        qemu_bin: Some(qemu_bin),
+        args: qemu_args,
+        desc,
+        script: None,
+        features: Vec::new(),
+    };
+
+    let config_json =
+        serde_json::to_string_pretty(&config).context("Failed to serialize configuration")?;
+    fs::write(&config_path, config_json).context("Failed to save config file")?;
+
+    println!(
+        "Imported '{}' from command line, saved to {:?}",
+        name, config_path
+    );
+
+    Ok(())
+}
@@ -36,7 +36,7 @@ pub fn init_command(shell: Option<Shell>, print_only: bool) -> Result<()> {
 
     // Check if already configured
     if let Ok(content) = fs::read_to_string(&rc_file) {
-        if content.contains("vex completions") || content.contains("vex init") {
+        if content.contains("COMPLETE=") || content.contains("vex init") {
             println!("✓ Shell completion for vex is already configured in {:?}", rc_file);
             println!("  To reconfigure, remove the existing vex completion line first.");
             return Ok(());
@@ -81,13 +81,21 @@ fn detect_shell() -> Option<Shell> {
 }
 
 /// Get the completion configuration line for a shell
+///
+/// Vex has no static completion script to source: `vex` itself is invoked
+/// with `COMPLETE=<shell>` to print shell-specific registration code, which
+/// in turn calls back into `vex` on every completion so the config list is
+/// never stale.
 fn get_completion_line(shell: Shell) -> String {
     match shell {
-        Shell::Bash => r#"eval "$(vex completions bash)""#.to_string(),
-        Shell::Zsh => r#"eval "$(vex completions zsh)""#.to_string(),
-        Shell::Fish => "vex completions fish | source".to_string(),
-        Shell::PowerShell => "Invoke-Expression (& vex completions powershell)".to_string(),
-        Shell::Elvish => "eval (vex completions elvish)".to_string(),
+        Shell::Bash => "source <(COMPLETE=bash vex)".to_string(),
+        Shell::Zsh => "source <(COMPLETE=zsh vex)".to_string(),
+        Shell::Fish => "COMPLETE=fish vex | source".to_string(),
+        Shell::PowerShell => {
+            r#"$env:COMPLETE="powershell"; vex | Out-String | Invoke-Expression; Remove-Item Env:\COMPLETE"#
+                .to_string()
+        }
+        Shell::Elvish => "eval (E:COMPLETE=elvish vex | slurp)".to_string(),
         _ => format!("# Unsupported shell: {:?}", shell),
     }
 }
@@ -0,0 +1,49 @@
+use anyhow::{Context, Result};
+use mlua::{Lua, Table, Variadic};
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+
+/// Run a config script and return the QEMU argv it builds up via `vm:arg(...)`
+pub fn build_args(
+    script_path: &Path,
+    name: &str,
+    desc: Option<&str>,
+    debug: bool,
+    vars: &HashMap<String, String>,
+) -> Result<Vec<String>> {
+    let source = fs::read_to_string(script_path)
+        .with_context(|| format!("Failed to read script {:?}", script_path))?;
+
+    let lua = Lua::new();
+    let args = Arc::new(Mutex::new(Vec::new()));
+
+    let vm = lua.create_table()?;
+    {
+        let args = args.clone();
+        let arg_fn = lua.create_function(move |_, (_vm, values): (Table, Variadic<String>)| {
+            args.lock().unwrap().extend(values.into_iter());
+            Ok(())
+        })?;
+        vm.set("arg", arg_fn)?;
+    }
+    vm.set("name", name)?;
+    vm.set("desc", desc.unwrap_or(""))?;
+    vm.set("debug", debug)?;
+
+    let vars_table = lua.create_table()?;
+    for (key, value) in vars {
+        vars_table.set(key.as_str(), value.as_str())?;
+    }
+    vm.set("vars", vars_table)?;
+
+    lua.globals().set("vm", vm)?;
+    lua.load(&source)
+        .set_name(script_path.to_string_lossy())
+        .exec()
+        .with_context(|| format!("Failed to evaluate script {:?}", script_path))?;
+
+    let args = args.lock().unwrap().clone();
+    Ok(args)
+}
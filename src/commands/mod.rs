@@ -1,19 +1,33 @@
-pub mod complete_configs;
-pub mod completions;
+pub mod complete;
+pub mod config;
 pub mod exec;
+pub mod feature;
+pub mod global;
+pub mod import;
+pub mod init;
 pub mod list;
+pub mod lua;
+pub mod monitor;
 pub mod print;
+pub mod qmp;
 pub mod remove;
 pub mod rename;
+pub mod run;
 pub mod save;
 
-pub use complete_configs::{CompleteConfigsArgs, complete_configs_command};
-pub use completions::{CompletionsArgs, completions_command};
+pub use complete::config_name_completer;
+pub use config::QemuConfig;
 pub use exec::{ExecArgs, exec_command};
+pub use feature::{FeatureArgs, feature_command};
+pub use global::{config_command, ConfigArgs};
+pub use import::{import_command, ImportArgs};
+pub use init::{init_command, InitArgs};
 pub use list::{ListArgs, list_command};
+pub use monitor::{MonitorArgs, monitor_command};
 pub use print::{PrintArgs, print_command};
 pub use remove::{RemoveArgs, remove_command};
 pub use rename::{RenameArgs, rename_command};
+pub use run::{ps_command, stop_command, PsArgs, StopArgs};
 pub use save::{SaveArgs, save_command};
 
 use clap::{Parser, Subcommand};
@@ -21,15 +35,25 @@ use clap::{Parser, Subcommand};
 #[derive(Subcommand)]
 pub enum Commands {
     Save(SaveArgs),
+    /// Import a full QEMU command line as a configuration
+    Import(ImportArgs),
     Rename(RenameArgs),
     Rm(RemoveArgs),
     List(ListArgs),
     Print(PrintArgs),
     Exec(ExecArgs),
-    Completions(CompletionsArgs),
-    /// Hidden command for shell completion
-    #[clap(hide = true)]
-    CompleteConfigs(CompleteConfigsArgs),
+    /// Send a QMP command to a running configuration
+    Monitor(MonitorArgs),
+    /// Manage reusable QEMU argument feature blocks
+    Feature(FeatureArgs),
+    /// Inspect and set global defaults
+    Config(ConfigArgs),
+    /// List running QEMU instances
+    Ps(PsArgs),
+    /// Stop a running QEMU instance
+    Stop(StopArgs),
+    /// Initialize shell completion for vex
+    Init(InitArgs),
 }
 
 #[derive(Parser)]
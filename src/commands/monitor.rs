@@ -0,0 +1,52 @@
+use anyhow::Result;
+use clap::Args;
+use serde_json::Value;
+
+use crate::commands::qmp::{qmp_socket_path, QmpClient};
+
+#[derive(Args)]
+#[clap(about = "Send a QMP command to a running configuration")]
+pub struct MonitorArgs {
+    /// Name of the running configuration to control
+    pub name: String,
+
+    /// QMP command to execute (e.g. query-status, system_powerdown)
+    pub qmp_command: String,
+
+    /// Optional `key=value` arguments to attach to the command
+    #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+    pub arguments: Vec<String>,
+}
+
+pub fn monitor_command(name: String, qmp_command: String, arguments: Vec<String>) -> Result<()> {
+    let socket = qmp_socket_path(&name)?;
+    if !socket.exists() {
+        anyhow::bail!(
+            "No QMP socket found for '{}'. Is the configuration running?",
+            name
+        );
+    }
+
+    let arguments = parse_arguments(&arguments);
+
+    let mut client = QmpClient::connect(&socket)?;
+    let result = client.execute(&qmp_command, arguments)?;
+    println!("{}", serde_json::to_string_pretty(&result)?);
+
+    Ok(())
+}
+
+/// Turn `key=value` pairs into the JSON object QMP expects as `arguments`
+fn parse_arguments(pairs: &[String]) -> Option<Value> {
+    if pairs.is_empty() {
+        return None;
+    }
+
+    let map = pairs
+        .iter()
+        .filter_map(|pair| pair.split_once('='))
+        .map(|(key, value)| (key.to_string(), Value::String(value.to_string())))
+        .collect();
+
+    Some(Value::Object(map))
+}
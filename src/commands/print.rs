@@ -0,0 +1,90 @@
+use anyhow::Result;
+use clap::Args;
+use std::collections::HashMap;
+
+use crate::commands::config::load_config;
+use crate::commands::global;
+
+#[derive(Args)]
+#[clap(about = "Print the details of a saved configuration")]
+pub struct PrintArgs {
+    /// Name of the configuration to print
+    pub name: String,
+
+    /// Emit a single copy-pasteable shell command instead of a debug dump
+    #[arg(long)]
+    pub cmdline: bool,
+}
+
+pub fn print_command(args: PrintArgs) -> Result<()> {
+    let PrintArgs { name, cmdline } = args;
+    let config = load_config(&name)?;
+    let global_config = global::load_global()?;
+    let (qemu_bin, _) = global::effective_qemu_bin(&config, &global_config);
+
+    if cmdline {
+        let mut parts = vec![qemu_bin];
+        parts.extend(
+            global::effective_args(&name, &config, &global_config, false, &HashMap::new())?
+                .into_iter()
+                .map(|arg| arg.value),
+        );
+
+        let line = parts
+            .iter()
+            .map(|part| shell_quote(part))
+            .collect::<Vec<_>>()
+            .join(" ");
+        println!("{}", line);
+        return Ok(());
+    }
+
+    if let Some(desc) = &config.desc {
+        println!("{} - {}", name, desc);
+    } else {
+        println!("{} - (no description)", name);
+    }
+    println!("  QEMU: {}", qemu_bin);
+    println!("  Args: {:?}", config.args);
+
+    Ok(())
+}
+
+/// Quote a single argument so it round-trips safely through a POSIX shell
+fn shell_quote(arg: &str) -> String {
+    if !arg.is_empty() && arg.chars().all(|c| c.is_ascii_alphanumeric() || "-_./=,:".contains(c)) {
+        arg.to_string()
+    } else {
+        format!("'{}'", arg.replace('\'', "'\\''"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plain_args_are_left_unquoted() {
+        assert_eq!(shell_quote("-m"), "-m");
+        assert_eq!(shell_quote("disk.img"), "disk.img");
+        assert_eq!(shell_quote("unix:/tmp/vex.sock,server,nowait"), "unix:/tmp/vex.sock,server,nowait");
+    }
+
+    #[test]
+    fn args_with_spaces_are_quoted() {
+        assert_eq!(
+            shell_quote("root=/dev/sda1 console=ttyS0"),
+            "'root=/dev/sda1 console=ttyS0'"
+        );
+    }
+
+    #[test]
+    fn embedded_single_quotes_are_escaped() {
+        assert_eq!(shell_quote("it's"), "'it'\\''s'");
+    }
+
+    #[test]
+    fn empty_arg_is_quoted() {
+        assert_eq!(shell_quote(""), "''");
+    }
+}
@@ -0,0 +1,88 @@
+use anyhow::{bail, Context, Result};
+use serde_json::{json, Value};
+use std::fs;
+use std::io::{BufRead, BufReader, Write};
+use std::os::unix::net::UnixStream;
+use std::path::{Path, PathBuf};
+
+use crate::commands::config::vex_dir;
+
+/// Path to the QMP control socket for a given config name (~/.vex/run/<name>.sock)
+pub fn qmp_socket_path(name: &str) -> Result<PathBuf> {
+    let dir = vex_dir()?.join("run");
+    if !dir.exists() {
+        fs::create_dir_all(&dir).context("Failed to create run directory")?;
+    }
+    Ok(dir.join(format!("{}.sock", name)))
+}
+
+/// The QEMU argument that wires up a QMP control socket for a config
+pub fn qmp_socket_arg(socket: &Path) -> String {
+    format!("unix:{},server,nowait", socket.display())
+}
+
+/// A QMP session, connected and past the `qmp_capabilities` handshake
+pub struct QmpClient {
+    stream: BufReader<UnixStream>,
+}
+
+impl QmpClient {
+    /// Connect to `path`, read the server greeting, and negotiate capabilities
+    pub fn connect(path: &Path) -> Result<Self> {
+        let stream = UnixStream::connect(path)
+            .with_context(|| format!("Failed to connect to QMP socket {:?}", path))?;
+        let mut client = QmpClient {
+            stream: BufReader::new(stream),
+        };
+
+        // The server greets with {"QMP": {...}} before accepting any commands
+        client.read_value()?;
+        client.execute("qmp_capabilities", None)?;
+
+        Ok(client)
+    }
+
+    /// Send `{"execute": command, "arguments": arguments}` and return the `return` payload
+    pub fn execute(&mut self, command: &str, arguments: Option<Value>) -> Result<Value> {
+        let mut request = json!({ "execute": command });
+        if let Some(arguments) = arguments {
+            request["arguments"] = arguments;
+        }
+
+        let mut payload = serde_json::to_string(&request).context("Failed to encode QMP command")?;
+        payload.push('\n');
+        self.stream
+            .get_mut()
+            .write_all(payload.as_bytes())
+            .context("Failed to write to QMP socket")?;
+
+        // Asynchronous {"event": ...} notifications can be interleaved with
+        // command replies, so keep reading until a line that actually carries
+        // our `return`/`error` shows up.
+        let response = loop {
+            let value = self.read_value()?;
+            if value.get("return").is_some() || value.get("error").is_some() {
+                break value;
+            }
+        };
+
+        if let Some(error) = response.get("error") {
+            bail!("QMP command '{}' failed: {}", command, error);
+        }
+
+        Ok(response.get("return").cloned().unwrap_or(Value::Null))
+    }
+
+    fn read_value(&mut self) -> Result<Value> {
+        let mut line = String::new();
+        let bytes_read = self
+            .stream
+            .read_line(&mut line)
+            .context("Failed to read from QMP socket")?;
+        if bytes_read == 0 {
+            bail!("QMP socket closed before a response was received");
+        }
+
+        serde_json::from_str(line.trim()).context("Failed to parse QMP response")
+    }
+}
@@ -0,0 +1,26 @@
+use anyhow::{Context, Result};
+use clap::Args;
+use std::fs;
+
+use crate::commands::config::config_file;
+
+#[derive(Args)]
+#[clap(about = "Delete a saved QEMU configuration")]
+pub struct RemoveArgs {
+    /// Name of the configuration to delete
+    pub name: String,
+}
+
+pub fn remove_command(args: RemoveArgs) -> Result<()> {
+    let RemoveArgs { name } = args;
+
+    let config_path = config_file(&name)?;
+    if !config_path.exists() {
+        anyhow::bail!("Configuration '{}' does not exist, cannot delete", name);
+    }
+
+    fs::remove_file(&config_path).context("Failed to delete config file")?;
+    println!("Configuration '{}' deleted", name);
+
+    Ok(())
+}
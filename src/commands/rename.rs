@@ -0,0 +1,79 @@
+use anyhow::{Context, Result};
+use clap::Args;
+use std::fs;
+
+use crate::commands::config::{config_file, QemuConfig};
+
+#[derive(Args)]
+#[clap(about = "Rename a saved QEMU configuration")]
+pub struct RenameArgs {
+    /// New description for the configuration (optional)
+    #[arg(short = 'd')]
+    pub desc: Option<String>,
+
+    /// Force overwrite if new name already exists (no prompt)
+    #[arg(short = 'y')]
+    pub force: bool,
+
+    /// Current name of the configuration
+    pub old_name: String,
+
+    /// New name for the configuration
+    pub new_name: String,
+}
+
+pub fn rename_command(args: RenameArgs) -> Result<()> {
+    let RenameArgs {
+        desc,
+        force,
+        old_name,
+        new_name,
+    } = args;
+
+    let old_config_path = config_file(&old_name)?;
+    if !old_config_path.exists() {
+        anyhow::bail!("Configuration '{}' does not exist, cannot rename", old_name);
+    }
+
+    let new_config_path = config_file(&new_name)?;
+    if new_config_path.exists() && !force {
+        println!("Configuration '{}' already exists, overwrite? [y/N]", new_name);
+        let mut input = String::new();
+        std::io::stdin().read_line(&mut input)?;
+        let input = input.trim().to_lowercase();
+        if input != "y" && input != "yes" {
+            println!("Rename cancelled");
+            return Ok(());
+        }
+    }
+
+    // Read the old configuration
+    let config_json =
+        fs::read_to_string(&old_config_path).context("Failed to read config file")?;
+    let mut config: QemuConfig =
+        serde_json::from_str(&config_json).context("Failed to deserialize configuration")?;
+
+    // Update description if provided
+    if let Some(new_desc) = desc {
+        config.desc = Some(new_desc);
+    }
+
+    // Save to new location
+    let new_config_json =
+        serde_json::to_string_pretty(&config).context("Failed to serialize configuration")?;
+    fs::write(&new_config_path, new_config_json).context("Failed to save new config file")?;
+
+    // Remove old configuration
+    fs::remove_file(&old_config_path).context("Failed to delete old config file")?;
+
+    if let Some(desc) = &config.desc {
+        println!(
+            "Configuration '{}' renamed to '{}' with description '{}'",
+            old_name, new_name, desc
+        );
+    } else {
+        println!("Configuration '{}' renamed to '{}'", old_name, new_name);
+    }
+
+    Ok(())
+}
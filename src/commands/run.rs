@@ -0,0 +1,216 @@
+use anyhow::{Context, Result};
+use clap::Args;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::thread;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use crate::commands::config::vex_dir;
+use crate::commands::qmp::QmpClient;
+
+/// A record of a running QEMU instance, written by `vex exec` and read back by `ps`/`stop`
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RunRecord {
+    pub name: String,
+    pub pid: u32,
+    pub started_at: u64,
+    pub command_line: Vec<String>,
+    pub qmp_socket: PathBuf,
+}
+
+#[derive(Args)]
+#[clap(about = "List running QEMU instances")]
+pub struct PsArgs;
+
+#[derive(Args)]
+#[clap(about = "Stop a running QEMU instance")]
+pub struct StopArgs {
+    /// Name of the running configuration to stop
+    pub name: String,
+
+    /// Skip the clean QMP shutdown and send SIGKILL immediately
+    #[arg(short = 'f', long)]
+    pub force: bool,
+}
+
+/// Get Vex's run-record storage directory (~/.vex/run)
+pub fn run_dir() -> Result<PathBuf> {
+    let dir = vex_dir()?.join("run");
+    if !dir.exists() {
+        fs::create_dir_all(&dir).context("Failed to create run directory")?;
+    }
+    Ok(dir)
+}
+
+fn run_file(name: &str) -> Result<PathBuf> {
+    Ok(run_dir()?.join(format!("{}.json", name)))
+}
+
+pub fn write_record(record: &RunRecord) -> Result<()> {
+    let path = run_file(&record.name)?;
+    let json = serde_json::to_string_pretty(record).context("Failed to serialize run record")?;
+    fs::write(&path, json).context("Failed to write run record")
+}
+
+pub fn load_record(name: &str) -> Result<Option<RunRecord>> {
+    let path = run_file(name)?;
+    if !path.exists() {
+        return Ok(None);
+    }
+
+    let json = fs::read_to_string(&path).context("Failed to read run record")?;
+    Ok(Some(
+        serde_json::from_str(&json).context("Failed to deserialize run record")?,
+    ))
+}
+
+pub fn remove_record(name: &str) -> Result<()> {
+    let path = run_file(name)?;
+    if path.exists() {
+        fs::remove_file(&path).context("Failed to remove run record")?;
+    }
+    Ok(())
+}
+
+/// Whether `pid` still refers to a live process
+pub fn is_alive(pid: u32) -> bool {
+    Path::new(&format!("/proc/{}", pid)).exists()
+}
+
+/// How long to wait for a pid to exit after a shutdown signal before escalating
+const SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(10);
+const SHUTDOWN_POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+/// Poll `pid` until it exits or `timeout` elapses; returns whether it exited
+fn wait_for_exit(pid: u32, timeout: Duration) -> bool {
+    let deadline = Instant::now() + timeout;
+    while is_alive(pid) {
+        if Instant::now() >= deadline {
+            return false;
+        }
+        thread::sleep(SHUTDOWN_POLL_INTERVAL);
+    }
+    true
+}
+
+pub fn ps_command(_args: PsArgs) -> Result<()> {
+    let dir = run_dir()?;
+    let entries = fs::read_dir(&dir).context("Failed to read run directory")?;
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .context("System clock is before the Unix epoch")?
+        .as_secs();
+
+    let mut live = Vec::new();
+
+    for entry in entries {
+        let entry = entry.context("Failed to read directory entry")?;
+        let path = entry.path();
+        if path.extension().map_or(false, |ext| ext == "json") {
+            if let Some(name) = path.file_stem().and_then(|s| s.to_str()) {
+                if let Some(record) = load_record(name)? {
+                    if is_alive(record.pid) {
+                        live.push(record);
+                    } else {
+                        // The process is gone; prune the stale record
+                        remove_record(name)?;
+                    }
+                }
+            }
+        }
+    }
+
+    if live.is_empty() {
+        println!("No running instances.");
+    } else {
+        println!("Running instances:");
+        for record in live {
+            let uptime = now.saturating_sub(record.started_at);
+            println!(
+                "  {} - pid {}, up {}s, qmp {}",
+                record.name,
+                record.pid,
+                uptime,
+                record.qmp_socket.display()
+            );
+        }
+    }
+
+    Ok(())
+}
+
+pub fn stop_command(args: StopArgs) -> Result<()> {
+    let StopArgs { name, force } = args;
+
+    let record = load_record(&name)?
+        .with_context(|| format!("No running instance found for '{}'", name))?;
+
+    if !is_alive(record.pid) {
+        println!(
+            "'{}' is not running (pid {} is gone); cleaning up stale record",
+            name, record.pid
+        );
+        remove_record(&name)?;
+        return Ok(());
+    }
+
+    if force {
+        send_signal(record.pid, "KILL")?;
+        println!("Sent SIGKILL to '{}' (pid {})", name, record.pid);
+        wait_for_exit(record.pid, SHUTDOWN_TIMEOUT);
+    } else {
+        match QmpClient::connect(&record.qmp_socket).and_then(|mut client| {
+            client.execute("system_powerdown", None)?;
+            Ok(())
+        }) {
+            Ok(()) => {
+                println!("Requested a clean shutdown of '{}' via QMP", name);
+                if !wait_for_exit(record.pid, SHUTDOWN_TIMEOUT) {
+                    println!(
+                        "'{}' (pid {}) ignored the ACPI shutdown request, falling back to SIGTERM",
+                        name, record.pid
+                    );
+                    send_signal(record.pid, "TERM")?;
+                    wait_for_exit(record.pid, SHUTDOWN_TIMEOUT);
+                }
+            }
+            Err(err) => {
+                println!(
+                    "QMP shutdown failed ({}), falling back to SIGTERM for '{}' (pid {})",
+                    err, name, record.pid
+                );
+                send_signal(record.pid, "TERM")?;
+                wait_for_exit(record.pid, SHUTDOWN_TIMEOUT);
+            }
+        }
+    }
+
+    // The VM may still be alive (hung, ignored every signal); only drop the
+    // record once it has actually exited, so `ps`/`stop` keep tracking it.
+    if is_alive(record.pid) {
+        println!(
+            "'{}' (pid {}) is still running; leaving its run record in place",
+            name, record.pid
+        );
+    } else {
+        remove_record(&name)?;
+    }
+
+    Ok(())
+}
+
+fn send_signal(pid: u32, signal: &str) -> Result<()> {
+    let status = Command::new("kill")
+        .arg(format!("-{}", signal))
+        .arg(pid.to_string())
+        .status()
+        .context("Failed to invoke kill")?;
+
+    if !status.success() {
+        anyhow::bail!("kill -{} {} failed", signal, pid);
+    }
+
+    Ok(())
+}
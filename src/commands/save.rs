@@ -0,0 +1,120 @@
+use anyhow::{Context, Result};
+use clap::Args;
+use std::fs;
+
+use crate::commands::config::{config_file, QemuConfig};
+
+#[derive(Args)]
+#[clap(about = "Save QEMU startup parameters as a configuration")]
+pub struct SaveArgs {
+    /// Force overwrite existing configuration (no prompt)
+    #[arg(short = 'y')]
+    pub force: bool,
+
+    /// Configuration name (for later execution/deletion)
+    pub name: String,
+
+    /// Configuration description (optional, use double quotes)
+    #[arg(short = 'd')]
+    pub desc: Option<String>,
+
+    /// Path to a Lua script that builds the argv at exec time, instead of static args
+    #[arg(long)]
+    pub script: Option<String>,
+
+    /// Reusable feature block to splice in at exec time (see `vex feature`), may repeat
+    #[arg(long = "feature")]
+    pub features: Vec<String>,
+
+    /// Path to QEMU executable (e.g., qemu-system-x86_64); omit to inherit
+    /// the default set by `vex config set --qemu-bin`
+    #[arg(long = "bin")]
+    pub qemu_bin: Option<String>,
+
+    /// QEMU startup arguments (e.g., -m 512 -hda disk.img)
+    #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+    pub qemu_args: Vec<String>,
+}
+
+pub fn save_command(args: SaveArgs) -> Result<()> {
+    let SaveArgs {
+        force,
+        name,
+        desc,
+        script,
+        features,
+        qemu_bin,
+        qemu_args,
+    } = args;
+
+    if let Some(script) = &script {
+        if !std::path::Path::new(script).exists() {
+            anyhow::bail!("Script '{}' does not exist", script);
+        }
+    }
+
+    let config_path = config_file(&name)?;
+
+    // Check if debug parameters -s or -S are present
+    let has_debug_args = qemu_args.iter().any(|arg| arg == "-s" || arg == "-S");
+
+    let mut final_args = qemu_args.clone();
+
+    if has_debug_args {
+        println!("Debug parameters '-s' or '-S' detected in startup arguments");
+        println!("These parameters are used to start GDB debugging server, but saving them to configuration may not be the best practice.");
+        println!("Suggestion: Skip saving these parameters and use 'vex exec -d' to start remote debugging mode");
+        println!("Skip saving debug parameters and use exec -d for remote debugging? [Y/n]");
+
+        let mut input = String::new();
+        std::io::stdin().read_line(&mut input)?;
+        let input = input.trim().to_lowercase();
+
+        if input.is_empty() || input == "y" || input == "yes" {
+            // User chose to skip debug parameters
+            final_args = qemu_args
+                .iter()
+                .filter(|&arg| arg != "-s" && arg != "-S")
+                .cloned()
+                .collect();
+            println!("Debug parameters have been skipped, saved configuration will not include -s or -S parameters");
+            println!("To start debugging mode, use: vex exec -d {}", name);
+        } else {
+            println!("Debug parameters will be included in the saved configuration");
+        }
+    }
+
+    let config = QemuConfig {
+        qemu_bin: qemu_bin.clone(),
+        args: final_args,
+        desc,
+        script,
+        features,
+    };
+
+    if config_path.exists() && !force {
+        println!("Configuration '{}' already exists, overwrite? [y/N]", name);
+        let mut input = String::new();
+        std::io::stdin().read_line(&mut input)?;
+        let input = input.trim().to_lowercase();
+        if input != "y" && input != "yes" {
+            println!("Save cancelled");
+            return Ok(());
+        }
+    }
+
+    let config_json =
+        serde_json::to_string_pretty(&config).context("Failed to serialize configuration")?;
+    fs::write(&config_path, config_json).context("Failed to save config file")?;
+
+    if let Some(desc) = &config.desc {
+        println!(
+            "Configuration '{}' with description '{}' saved to {:?}",
+            name, desc, config_path
+        );
+    } else {
+        println!("Configuration '{}' saved to {:?}", name, config_path);
+    }
+
+    Ok(())
+}